@@ -1,13 +1,321 @@
 use sfml::{audio::*, graphics::*, system::*, window::*};
 use std::f32::consts::PI;
+use std::time::Instant;
 
-const TARGET_FPS: u32 = 240;
-const WIDTH: u32 = 1200;
-const HEIGHT: u32 = 800;
-const ARC_COUNT: u32 = 21;
-const ARC_CENTER: (f32, f32) = (WIDTH as f32 * 0.5, HEIGHT as f32 * 0.9);
-const TIME_SECS: f32 = 900.0; // in seconds
-const GLOW_DURATION: f32 = 500.0; // in milliseconds
+/// Tap-tempo presses further apart than this are treated as the start of a new tempo,
+/// not as part of the same tap sequence.
+const MAX_TAP_GAP_SECS: f32 = 20.0;
+
+const ONE_LOOP: f32 = 2.0 * PI;
+
+/// Height, in pixels, of the draggable seekbar along the bottom of the window
+const SEEKER_HEIGHT: f32 = 10.0;
+
+/// Sample rate, in Hz, used to synthesize each arc's tone
+const SAMPLE_RATE: u32 = 44_100;
+
+/// Length, in seconds, of each synthesized collision tone
+const NOTE_DURATION_SECS: f32 = 0.3;
+
+/// Semitone offsets of a major pentatonic scale, cycled across the arcs
+const PENTATONIC_SCALE: [i32; 5] = [0, 2, 4, 7, 9];
+
+/// Default combo-style palette cycled across the arcs; swap `Polyrhythm::palette` for a
+/// different theme.
+fn default_palette() -> Vec<Color> {
+    vec![
+        Color::rgb(255, 192, 0),
+        Color::rgb(0, 202, 0),
+        Color::rgb(18, 124, 255),
+        Color::rgb(242, 24, 57),
+    ]
+}
+
+/// A selectable oscillator shape for the procedural collision tones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Waveform {
+    Sine,
+    Square,
+    Triangle,
+    Saw,
+}
+
+impl Waveform {
+    /// Sample this waveform at `phase` (fractional part of a cycle, in `[0.0, 1.0)`).
+    fn sample(self, phase: f32) -> f32 {
+        match self {
+            Waveform::Sine => (phase * ONE_LOOP).sin(),
+            Waveform::Square => {
+                if phase < 0.5 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            Waveform::Triangle => 4.0 * (phase - 0.5).abs() - 1.0,
+            Waveform::Saw => 2.0 * phase - 1.0,
+        }
+    }
+
+    /// The next waveform in the cycle, wrapping back to [`Waveform::Sine`] after [`Waveform::Saw`].
+    fn next(self) -> Self {
+        match self {
+            Waveform::Sine => Waveform::Square,
+            Waveform::Square => Waveform::Triangle,
+            Waveform::Triangle => Waveform::Saw,
+            Waveform::Saw => Waveform::Sine,
+        }
+    }
+}
+
+/// Map arc index `i` to a frequency on a major pentatonic scale rooted at `base_freq`, rising
+/// one scale degree per arc and one octave every five arcs.
+fn scale_frequency(i: u32, base_freq: f32) -> f32 {
+    let octave = i / PENTATONIC_SCALE.len() as u32;
+    let semitone = PENTATONIC_SCALE[(i % PENTATONIC_SCALE.len() as u32) as usize] + 12 * octave as i32;
+    base_freq * 2f32.powf(semitone as f32 / 12.0)
+}
+
+/// Short ADSR envelope so notes don't click: a fast attack and decay down to a sustain level,
+/// then a release back to silence before `duration` runs out.
+fn adsr_envelope(t: f32, duration: f32) -> f32 {
+    const ATTACK: f32 = 0.01;
+    const DECAY: f32 = 0.05;
+    const SUSTAIN_LEVEL: f32 = 0.7;
+    const RELEASE: f32 = 0.1;
+
+    if t < ATTACK {
+        t / ATTACK
+    } else if t < ATTACK + DECAY {
+        1.0 - (1.0 - SUSTAIN_LEVEL) * (t - ATTACK) / DECAY
+    } else if t < duration - RELEASE {
+        SUSTAIN_LEVEL
+    } else {
+        SUSTAIN_LEVEL * ((duration - t) / RELEASE).max(0.0)
+    }
+}
+
+/// Synthesize `NOTE_DURATION_SECS` worth of `i16` PCM samples for a single tone.
+fn synth_tone(freq: f32, waveform: Waveform) -> Vec<i16> {
+    let sample_count = (SAMPLE_RATE as f32 * NOTE_DURATION_SECS) as usize;
+
+    (0..sample_count)
+        .map(|n| {
+            let t = n as f32 / SAMPLE_RATE as f32;
+            let phase = (t * freq).fract();
+            let amplitude = waveform.sample(phase) * adsr_envelope(t, NOTE_DURATION_SECS);
+            (amplitude * i16::MAX as f32) as i16
+        })
+        .collect()
+}
+
+/// A single playable audio channel, agnostic of the underlying audio library.
+trait Voice {
+    fn play(&mut self);
+    fn set_volume(&mut self, volume: f32);
+    fn is_playing(&self) -> bool;
+}
+
+impl<'a> Voice for Sound<'a> {
+    fn play(&mut self) {
+        Sound::play(self);
+    }
+
+    fn set_volume(&mut self, volume: f32) {
+        Sound::set_volume(self, volume);
+    }
+
+    fn is_playing(&self) -> bool {
+        self.status() == SoundStatus::PLAYING
+    }
+}
+
+/// Audio playback abstracted away from SFML, so a rodio/cpal backend or [`SilentAudioBackend`]
+/// (for running the collision logic without opening an audio device) can be dropped in instead.
+trait AudioBackend {
+    fn voice_count(&self) -> usize;
+    fn voice(&mut self, idx: usize) -> &mut dyn Voice;
+    fn stop(&mut self, idx: usize);
+
+    /// Play voice `idx` unless it's already playing; returns `true` if it actually retriggered.
+    fn trigger(&mut self, idx: usize) -> bool {
+        if self.voice(idx).is_playing() {
+            false
+        } else {
+            self.voice(idx).play();
+            true
+        }
+    }
+}
+
+/// The default [`AudioBackend`], backed by SFML's `Sound`/`SoundBuffer`.
+struct SfmlAudioBackend<'a> {
+    voices: Vec<Sound<'a>>,
+}
+
+impl<'a> SfmlAudioBackend<'a> {
+    fn new(arc_count: u32, waveform: Waveform, base_freq: f32) -> Self {
+        Self {
+            voices: (0..arc_count)
+                .map(|i| {
+                    let samples = synth_tone(scale_frequency(i, base_freq), waveform);
+                    let buffer: Box<sfml::SfBox<SoundBuffer>> =
+                        Box::new(SoundBuffer::from_samples(&samples, 1, SAMPLE_RATE).unwrap());
+
+                    let mut sound = Sound::with_buffer(Box::leak(buffer));
+                    sound.set_volume(15.0);
+                    sound
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<'a> AudioBackend for SfmlAudioBackend<'a> {
+    fn voice_count(&self) -> usize {
+        self.voices.len()
+    }
+
+    fn voice(&mut self, idx: usize) -> &mut dyn Voice {
+        &mut self.voices[idx]
+    }
+
+    fn stop(&mut self, idx: usize) {
+        self.voices[idx].stop();
+    }
+}
+
+/// A [`Voice`] that tracks play/stop state in memory without touching an audio device. Mirrors
+/// [`SfmlAudioBackend`]'s real playback length so `trigger()`'s debounce behaves the same way
+/// under `--silent` as it does with actual audio.
+struct SilentVoice {
+    started_at: Option<Instant>,
+}
+
+impl Voice for SilentVoice {
+    fn play(&mut self) {
+        self.started_at = Some(Instant::now());
+    }
+
+    fn set_volume(&mut self, _volume: f32) {}
+
+    fn is_playing(&self) -> bool {
+        self.started_at
+            .is_some_and(|started_at| started_at.elapsed().as_secs_f32() < NOTE_DURATION_SECS)
+    }
+}
+
+/// An [`AudioBackend`] that opens no audio device at all, so the collision logic can run (e.g.
+/// under `--silent`) wherever SFML's audio module isn't available.
+struct SilentAudioBackend {
+    voices: Vec<SilentVoice>,
+}
+
+impl SilentAudioBackend {
+    fn new(arc_count: u32) -> Self {
+        Self {
+            voices: (0..arc_count)
+                .map(|_| SilentVoice { started_at: None })
+                .collect(),
+        }
+    }
+}
+
+impl AudioBackend for SilentAudioBackend {
+    fn voice_count(&self) -> usize {
+        self.voices.len()
+    }
+
+    fn voice(&mut self, idx: usize) -> &mut dyn Voice {
+        &mut self.voices[idx]
+    }
+
+    fn stop(&mut self, idx: usize) {
+        self.voices[idx].started_at = None;
+    }
+}
+
+/// Runtime-configurable knobs for a [`Polyrhythm`], assembled via [`PolyrhythmBuilder`].
+struct PolyrhythmConfig {
+    width: u32,
+    height: u32,
+    arc_count: u32,
+    cycle_seconds: f32,
+    glow_duration: f32,
+    target_fps: u32,
+}
+
+impl PolyrhythmConfig {
+    /// The point all arcs are centered on and the balls orbit around.
+    fn arc_center(&self) -> (f32, f32) {
+        (self.width as f32 * 0.5, self.height as f32 * 0.9)
+    }
+}
+
+impl Default for PolyrhythmConfig {
+    fn default() -> Self {
+        Self {
+            width: 1200,
+            height: 800,
+            arc_count: 21,
+            cycle_seconds: 900.0, // in seconds
+            glow_duration: 500.0, // in milliseconds
+            target_fps: 240,
+        }
+    }
+}
+
+/// Builds a [`Polyrhythm`] with a runtime-configurable size, arc count, cycle length and glow
+/// duration, so users can spin up differently-sized polyrhythms without recompiling.
+#[derive(Default)]
+struct PolyrhythmBuilder {
+    config: PolyrhythmConfig,
+    silent: bool,
+}
+
+impl PolyrhythmBuilder {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_resolution(mut self, width: u32, height: u32) -> Self {
+        self.config.width = width;
+        self.config.height = height;
+        self
+    }
+
+    fn with_arc_count(mut self, arc_count: u32) -> Self {
+        self.config.arc_count = arc_count;
+        self
+    }
+
+    fn with_cycle_seconds(mut self, cycle_seconds: f32) -> Self {
+        self.config.cycle_seconds = cycle_seconds;
+        self
+    }
+
+    fn with_glow_duration(mut self, glow_duration: f32) -> Self {
+        self.config.glow_duration = glow_duration;
+        self
+    }
+
+    /// Drive the collision logic with a [`SilentAudioBackend`] instead of opening an audio
+    /// device, for running headless.
+    fn with_silent_audio(mut self) -> Self {
+        self.silent = true;
+        self
+    }
+
+    fn build<'a>(self) -> Polyrhythm<'a> {
+        assert!(self.config.arc_count > 0, "arc count must be positive");
+        assert!(
+            self.config.cycle_seconds > 0.0,
+            "cycle time must be positive"
+        );
+
+        Polyrhythm::new(self.config, self.silent)
+    }
+}
 
 fn main() {
     let context_settings = ContextSettings {
@@ -15,14 +323,31 @@ fn main() {
         ..Default::default()
     };
 
+    // An optional `--arc-count N` argument lets users spin up a differently-sized polyrhythm
+    // without recompiling, exercising `PolyrhythmBuilder::with_arc_count` beyond its default.
+    // `--silent` runs the collision logic against a `SilentAudioBackend` instead, for
+    // environments without an audio device.
+    let mut builder = PolyrhythmBuilder::new();
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(pos) = args.iter().position(|arg| arg == "--arc-count") {
+        if let Some(arc_count) = args.get(pos + 1).and_then(|s| s.parse().ok()) {
+            builder = builder.with_arc_count(arc_count);
+        }
+    }
+    if args.iter().any(|arg| arg == "--silent") {
+        builder = builder.with_silent_audio();
+    }
+
+    let mut polyrhythm = builder.build();
+
     let mut window = RenderWindow::new(
-        (WIDTH, HEIGHT),
+        (polyrhythm.config.width, polyrhythm.config.height),
         "Polyrhythm",
         Style::CLOSE,
         &context_settings,
     );
 
-    window.set_framerate_limit(TARGET_FPS);
+    window.set_framerate_limit(polyrhythm.config.target_fps);
 
     let mut info_text = {
         let mut font: Box<sfml::SfBox<Font>> = Box::new(Font::from_file("Hack NF.ttf").unwrap());
@@ -37,23 +362,53 @@ fn main() {
     info_text.set_position((10.0, 10.0));
     info_text.set_fill_color(Color::WHITE);
 
-    let mut polyrhythm = Polyrhythm::new();
     let mut dtc = Clock::start();
 
     while window.is_open() {
         let dt = dtc.restart();
         while let Some(event) = window.poll_event() {
-            if let Event::Closed = event {
-                window.close();
+            match event {
+                Event::Closed => window.close(),
+                Event::MouseButtonPressed {
+                    button: mouse::Button::Left,
+                    x,
+                    y,
+                } => {
+                    if polyrhythm
+                        .seeker_bounds()
+                        .contains(Vector2::new(x as f32, y as f32))
+                    {
+                        polyrhythm.seeker_drag = true;
+                        polyrhythm.seek_to(x as f32);
+                    } else if let Some(idx) = polyrhythm.nearest_arc(x as f32, y as f32) {
+                        polyrhythm.toggle_arc(idx);
+                    }
+                }
+                Event::MouseButtonReleased {
+                    button: mouse::Button::Left,
+                    ..
+                } => polyrhythm.seeker_drag = false,
+                Event::MouseMoved { x, .. } if polyrhythm.seeker_drag => {
+                    polyrhythm.seek_to(x as f32);
+                }
+                Event::KeyPressed {
+                    code: Key::Space, ..
+                } => polyrhythm.paused = !polyrhythm.paused,
+                Event::KeyPressed { code: Key::R, .. } => polyrhythm.reset(),
+                Event::KeyPressed { code: Key::T, .. } => polyrhythm.tap_tempo(),
+                Event::KeyPressed { code: Key::W, .. } => polyrhythm.cycle_waveform(),
+                _ => {}
             }
         }
 
         let fps = 1.0 / dt.as_seconds();
         info_text.set_string(&format!(
-            "FPS: {:.0}\nCollisions: {}\nTime Elapsed: {:.0}s/{TIME_SECS:.0}s",
+            "FPS: {:.0}\nCollisions: {}\nTime Elapsed: {:.0}s/{:.0}s{}",
             fps,
             polyrhythm.num_collisions,
             polyrhythm.elapsed_time.as_seconds(),
+            polyrhythm.period,
+            if polyrhythm.paused { "\nPaused" } else { "" },
         ));
 
         window.clear(Color::BLACK);
@@ -64,9 +419,15 @@ fn main() {
 }
 
 struct Polyrhythm<'a> {
+    /// Size, arc count and timing knobs this instance was built with
+    config: PolyrhythmConfig,
+
     /// Arcs
     arcs: Vec<Arc<'a>>,
 
+    /// Combo-style palette cycled across the arcs; exposed so users can theme the visualization
+    palette: Vec<Color>,
+
     /// The rectangle covering parts of circle so that it looks like an arc
     rect: RectangleShape<'a>,
 
@@ -76,27 +437,64 @@ struct Polyrhythm<'a> {
     /// The elasped time since the program is running
     elapsed_time: Time,
 
-    /// Player
-    players: Vec<Music<'a>>,
+    /// Audio backend driving each arc's voice; boxed so [`SilentAudioBackend`] (or a future
+    /// rodio/cpal backend) can be dropped in instead of [`SfmlAudioBackend`]
+    audio: Box<dyn AudioBackend + 'a>,
+
+    /// Oscillator shape used when synthesizing each arc's tone; cycled at runtime with the `W`
+    /// key, which regenerates every arc's voice from the new waveform
+    waveform: Waveform,
+
+    /// Root frequency the per-arc pentatonic scale is built from, in Hz
+    base_freq: f32,
 
     /// Number of times ball has touched either side
     num_collisions: usize,
 
     collision: Vec<bool>,
+
+    /// Track for the draggable timeline seekbar
+    seeker_track: RectangleShape<'a>,
+
+    /// Handle showing the current playback position on the seekbar
+    seeker_handle: RectangleShape<'a>,
+
+    /// `true` while the seekbar handle is being dragged
+    seeker_drag: bool,
+
+    /// The base cycle period, in seconds; seeded from [`PolyrhythmConfig::cycle_seconds`] but
+    /// mutable so tap tempo can change it live
+    period: f32,
+
+    /// `true` while playback is paused; freezes `elapsed_time` accumulation in [`Polyrhythm::draw`]
+    paused: bool,
+
+    /// Timestamp of the previous tap-tempo key press, if any
+    last_tap: Option<Instant>,
+
+    /// `true` if [`Polyrhythm::audio`] is a [`SilentAudioBackend`] rather than an audible one,
+    /// so [`Polyrhythm::cycle_waveform`] knows not to swap in an SFML backend
+    silent: bool,
 }
 
 impl<'a> Polyrhythm<'a> {
-    fn new() -> Self {
+    fn new(config: PolyrhythmConfig, silent: bool) -> Self {
+        let waveform = Waveform::Sine;
+        let base_freq = 220.0; // A3
+        let palette = default_palette();
+        let arc_center = config.arc_center();
+
+        let audio: Box<dyn AudioBackend + 'a> = if silent {
+            Box::new(SilentAudioBackend::new(config.arc_count))
+        } else {
+            Box::new(SfmlAudioBackend::new(config.arc_count, waveform, base_freq))
+        };
+        debug_assert_eq!(audio.voice_count(), config.arc_count as usize);
+
         Self {
             num_collisions: 0,
-            players: (0..ARC_COUNT)
-                .map(|i| {
-                    let mut m = Music::from_file(&format!("sounds/key-{i}.wav")).unwrap();
-                    m.set_volume(15.0);
-                    m
-                })
-                .collect(),
-            collision: (0..ARC_COUNT).map(|_| false).collect(),
+            audio,
+            collision: (0..config.arc_count).map(|_| false).collect(),
             elapsed_time: Time::milliseconds(100),
             circle: {
                 let radius = 5.0;
@@ -109,22 +507,175 @@ impl<'a> Polyrhythm<'a> {
             rect: {
                 let mut rect = RectangleShape::new();
                 rect.set_fill_color(Color::BLACK);
-                rect.set_position((0.0, HEIGHT as f32 * 0.9));
-                rect.set_size((WIDTH as f32, 300.0));
+                rect.set_position((0.0, config.height as f32 * 0.9));
+                rect.set_size((config.width as f32, 300.0));
                 rect
             },
-            arcs: (0..ARC_COUNT)
+            arcs: (0..config.arc_count)
                 .map(|i| {
-                    let radius =
-                        50.0 + ((WIDTH as f32 / 2.0) / (ARC_COUNT as f32 + 3.0) * i as f32);
-                    Arc::new(radius)
+                    let radius = 50.0
+                        + ((config.width as f32 / 2.0) / (config.arc_count as f32 + 3.0)
+                            * i as f32);
+                    let color = palette[i as usize % palette.len()];
+                    Arc::new(radius, color, arc_center, config.glow_duration)
                 })
                 .collect::<Vec<_>>(),
+            palette,
+            seeker_track: {
+                let mut track = RectangleShape::new();
+                track.set_fill_color(Color::rgb(40, 40, 40));
+                track.set_position((0.0, config.height as f32 - SEEKER_HEIGHT));
+                track.set_size((config.width as f32, SEEKER_HEIGHT));
+                track
+            },
+            seeker_handle: {
+                let mut handle = RectangleShape::new();
+                handle.set_fill_color(Color::WHITE);
+                handle.set_position((0.0, config.height as f32 - SEEKER_HEIGHT));
+                handle.set_size((4.0, SEEKER_HEIGHT));
+                handle
+            },
+            seeker_drag: false,
+            period: config.cycle_seconds,
+            paused: false,
+            last_tap: None,
+            silent,
+            waveform,
+            base_freq,
+            config,
+        }
+    }
+
+    /// Reset `elapsed_time` and the collision count to zero and stop every voice, so playback
+    /// starts over and each arc's tone retriggers on its next collision.
+    fn reset(&mut self) {
+        self.elapsed_time = Time::ZERO;
+        self.num_collisions = 0;
+
+        for (i, collision) in self.collision.iter_mut().enumerate() {
+            *collision = false;
+            self.audio.stop(i);
+        }
+    }
+
+    /// Adjust the base cycle period to the interval since the previous tap, ignoring gaps
+    /// longer than [`MAX_TAP_GAP_SECS`] (treated as the start of a new tempo instead). The
+    /// per-arc `speed` in [`Polyrhythm::draw`] is derived from `self.period`, so the whole
+    /// polyrhythm re-locks to the tapped tempo live.
+    fn tap_tempo(&mut self) {
+        let now = Instant::now();
+
+        if let Some(last_tap) = self.last_tap {
+            let gap = now.duration_since(last_tap).as_secs_f32();
+
+            if gap <= MAX_TAP_GAP_SECS {
+                self.period = gap;
+            }
+        }
+
+        self.last_tap = Some(now);
+    }
+
+    /// Cycle to the next [`Waveform`] and rebuild every arc's voice from it, so the timbre
+    /// change is audible on the very next collision.
+    fn cycle_waveform(&mut self) {
+        self.waveform = self.waveform.next();
+
+        if self.silent {
+            self.audio = Box::new(SilentAudioBackend::new(self.config.arc_count));
+        } else {
+            self.audio = Box::new(SfmlAudioBackend::new(
+                self.config.arc_count,
+                self.waveform,
+                self.base_freq,
+            ));
+        }
+    }
+
+    /// Bounds of the draggable seekbar, used both for hit-testing mouse clicks and for
+    /// positioning the handle in [`Polyrhythm::draw`].
+    fn seeker_bounds(&self) -> FloatRect {
+        FloatRect::new(
+            0.0,
+            self.config.height as f32 - SEEKER_HEIGHT,
+            self.config.width as f32,
+            SEEKER_HEIGHT,
+        )
+    }
+
+    /// Find the arc whose radius is closest to the click's distance from the arc center, as in
+    /// the distance-squared selection in the asteroids-genetic viewer. The arcs are concentric,
+    /// so comparing radii (rather than searching 2D space) is enough to pick the ring under the
+    /// cursor.
+    fn nearest_arc(&self, x: f32, y: f32) -> Option<usize> {
+        // A click has to land within this many pixels of a ring's outline to count as hitting
+        // it; otherwise clicks on empty background (e.g. the FPS overlay) would always toggle
+        // whichever arc happens to be numerically closest.
+        const CLICK_TOLERANCE_PX: f32 = 6.0;
+
+        let arc_center = self.config.arc_center();
+
+        // The bottom half of every ring is painted over by `self.rect` in `draw` and never
+        // rendered, so a click down there has no visible arc to justify toggling one.
+        if y > arc_center.1 {
+            return None;
+        }
+
+        let dx = x - arc_center.0;
+        let dy = y - arc_center.1;
+        let click_radius = (dx * dx + dy * dy).sqrt();
+
+        self.arcs
+            .iter()
+            .map(|arc| arc.arc_shape.radius())
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (a - click_radius)
+                    .abs()
+                    .partial_cmp(&(b - click_radius).abs())
+                    .unwrap()
+            })
+            .filter(|(_, radius)| (radius - click_radius).abs() <= CLICK_TOLERANCE_PX)
+            .map(|(idx, _)| idx)
+    }
+
+    /// Cycle arc `idx` between normal / muted / soloed.
+    fn toggle_arc(&mut self, idx: usize) {
+        self.arcs[idx].state = self.arcs[idx].state.toggled();
+    }
+
+    /// Jump the whole simulation to the point under `mouse_x` on the seekbar.
+    ///
+    /// Every ball position in [`Polyrhythm::draw`] is a pure function of `elapsed_time`, so
+    /// seeking is just reassigning it and re-syncing the players. The usual
+    /// `(0.0..0.005).contains(&(distance % PI))` sampling window would otherwise fire a bogus
+    /// collision/sound burst the first frame after the jump, so collisions are suppressed here
+    /// and `num_collisions` is recomputed analytically as the number of half-period crossings
+    /// each arc has already completed at the new time.
+    fn seek_to(&mut self, mouse_x: f32) {
+        let bounds = self.seeker_bounds();
+        let jump_percent = ((mouse_x - bounds.left) / bounds.width).clamp(0.0, 1.0);
+        self.elapsed_time = Time::seconds(jump_percent * self.period);
+
+        let arc_count = self.config.arc_count;
+
+        self.num_collisions = 0;
+        for (i, collision) in self.collision.iter_mut().enumerate() {
+            *collision = false;
+            self.audio.stop(i);
+
+            let speed = (ONE_LOOP * (arc_count - i as u32) as f32) / self.period;
+            let half_periods_elapsed = (speed * self.elapsed_time.as_seconds() / PI).floor();
+            self.num_collisions += half_periods_elapsed as usize;
         }
     }
 
     fn draw(&mut self, window: &mut RenderWindow, dt: Time) {
-        self.elapsed_time += dt;
+        if !self.paused {
+            self.elapsed_time += dt;
+        }
+
+        let any_soloed = self.arcs.iter().any(|arc| arc.state == ArcState::Soloed);
 
         for idx in 0..self.arcs.len() {
             let itm = self.arcs.get_mut(idx).unwrap();
@@ -133,10 +684,14 @@ impl<'a> Polyrhythm<'a> {
             if *collision {
                 itm.glow_start();
 
-                // play sound
-                if self.players[idx].status() != SoundStatus::PLAYING {
-                    self.num_collisions += 1;
-                    self.players[idx].play();
+                let should_play = match itm.state {
+                    ArcState::Muted => false,
+                    ArcState::Soloed => true,
+                    ArcState::Normal => !any_soloed,
+                };
+
+                if should_play {
+                    self.audio.trigger(idx);
                 }
 
                 *collision = false;
@@ -147,11 +702,23 @@ impl<'a> Polyrhythm<'a> {
 
         window.draw(&self.rect);
 
-        for i in 0..ARC_COUNT {
-            let arc_radius = 50.0 + ((WIDTH as f32 / 2.0) / (ARC_COUNT as f32 + 3.0) * i as f32);
+        let arc_count = self.config.arc_count;
+        let arc_center = self.config.arc_center();
+
+        for i in 0..arc_count {
+            let arc_radius =
+                50.0 + ((self.config.width as f32 / 2.0) / (arc_count as f32 + 3.0) * i as f32);
+
+            let speed = (ONE_LOOP * (arc_count - i) as f32) / self.period;
+
+            // Count each half-period crossing once, on the frame it happens, so the running
+            // total matches what `seek_to` computes analytically.
+            let prev_elapsed = if self.paused { self.elapsed_time } else { self.elapsed_time - dt };
+            let prev_crossings = (speed * prev_elapsed.as_seconds() / PI).floor();
+            if (speed * self.elapsed_time.as_seconds() / PI).floor() > prev_crossings {
+                self.num_collisions += 1;
+            }
 
-            static ONE_LOOP: f32 = 2.0 * PI;
-            let speed = (ONE_LOOP * (50 - i) as f32) / TIME_SECS;
             let distance = PI + speed * self.elapsed_time.as_seconds();
             let mod_distance = distance % (2.0 * PI);
             let adjusted_distance = if mod_distance >= PI {
@@ -161,18 +728,32 @@ impl<'a> Polyrhythm<'a> {
             };
 
             let (x, y) = (
-                ARC_CENTER.0 + arc_radius * adjusted_distance.cos(),
-                ARC_CENTER.1 + arc_radius * adjusted_distance.sin(),
+                arc_center.0 + arc_radius * adjusted_distance.cos(),
+                arc_center.1 + arc_radius * adjusted_distance.sin(),
             );
 
             if (0.0..0.005).contains(&(distance % PI)) {
                 self.collision[i as usize] = true;
             }
 
+            self.arcs[i as usize].base_color = self.palette[i as usize % self.palette.len()];
+            let arc = &self.arcs[i as usize];
             self.circle.set_position((x, y));
+            self.circle
+                .set_fill_color(arc.state.tint(lerp_color(arc.base_color, Color::WHITE, 0.5)));
 
             window.draw(&self.circle);
         }
+
+        let bounds = self.seeker_bounds();
+        let progress = (self.elapsed_time.as_seconds() / self.period).clamp(0.0, 1.0);
+        self.seeker_handle.set_position((
+            bounds.left + progress * bounds.width - self.seeker_handle.size().x / 2.0,
+            bounds.top,
+        ));
+
+        window.draw(&self.seeker_track);
+        window.draw(&self.seeker_handle);
     }
 }
 
@@ -180,23 +761,36 @@ struct Arc<'a> {
     glow_start_time: Option<Time>,
     arc_shape: CircleShape<'a>,
     elapsed_time: Time,
+
+    /// This arc's color, drawn from the palette; the glow and the traveling circle both tint
+    /// toward white from this base
+    base_color: Color,
+
+    /// How long, in milliseconds, the glow takes to fade in, and again to fade back out
+    glow_duration: f32,
+
+    /// Solo/mute state toggled by clicking this arc
+    state: ArcState,
 }
 
 impl<'a> Arc<'a> {
-    fn new(radius: f32) -> Self {
+    fn new(radius: f32, base_color: Color, arc_center: (f32, f32), glow_duration: f32) -> Self {
         Self {
             glow_start_time: None,
             arc_shape: {
                 let mut arc = CircleShape::new(radius, 100);
                 arc.set_origin((radius, radius));
                 arc.set_outline_thickness(2.0);
-                arc.set_outline_color(Color::rgb(50, 50, 50));
-                arc.set_position(ARC_CENTER);
+                arc.set_outline_color(lerp_color(Color::BLACK, base_color, 0.196_078_43));
+                arc.set_position(arc_center);
                 arc.set_fill_color(Color::TRANSPARENT);
 
                 arc
             },
             elapsed_time: Time::ZERO,
+            base_color,
+            glow_duration,
+            state: ArcState::Normal,
         }
     }
 
@@ -206,25 +800,24 @@ impl<'a> Arc<'a> {
         if let Some(glow_start_time) = self.glow_start_time {
             let time_since_glow_start = self.elapsed_time - glow_start_time;
 
-            if time_since_glow_start.as_milliseconds() > GLOW_DURATION as i32 {
+            let fade_factor = if time_since_glow_start.as_milliseconds() > self.glow_duration as i32
+            {
                 // fade out
-
-                let fade_factor = (time_since_glow_start.as_milliseconds() as f32 - GLOW_DURATION)
-                    / GLOW_DURATION;
-                let fade_factor = 1.0 - fade_factor.clamp(0.196_078_43, 1.0);
-
-                let color_val = ((255.0 * fade_factor) as u8).clamp(50, 255);
-                self.arc_shape
-                    .set_outline_color(Color::rgb(color_val, color_val, color_val));
+                let fade_factor = (time_since_glow_start.as_milliseconds() as f32
+                    - self.glow_duration)
+                    / self.glow_duration;
+                1.0 - fade_factor.clamp(0.196_078_43, 1.0)
             } else {
                 // fade in
+                (time_since_glow_start.as_milliseconds() as f32 / self.glow_duration)
+                    .clamp(0.196_078_43, 1.0)
+            };
 
-                let fade_factor = time_since_glow_start.as_milliseconds() as f32 / GLOW_DURATION;
-                let fade_factor = fade_factor.clamp(0.196_078_43, 1.0);
-                let color_val = (255.0 * fade_factor) as u8;
-                self.arc_shape
-                    .set_outline_color(Color::rgb(color_val, color_val, color_val));
-            }
+            let color = lerp_color(self.base_color, Color::WHITE, fade_factor);
+            self.arc_shape.set_outline_color(self.state.tint(color));
+        } else {
+            let idle = lerp_color(Color::BLACK, self.base_color, 0.196_078_43);
+            self.arc_shape.set_outline_color(self.state.tint(idle));
         }
 
         window.draw(&self.arc_shape);
@@ -234,3 +827,48 @@ impl<'a> Arc<'a> {
         self.glow_start_time = Some(self.elapsed_time);
     }
 }
+
+/// Per-arc solo/mute state, cycled by clicking the arc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArcState {
+    Normal,
+    Muted,
+    Soloed,
+}
+
+impl ArcState {
+    fn toggled(self) -> Self {
+        match self {
+            ArcState::Normal => ArcState::Muted,
+            ArcState::Muted => ArcState::Soloed,
+            ArcState::Soloed => ArcState::Normal,
+        }
+    }
+
+    /// Dim a muted arc's outline, brighten a soloed one, and leave a normal arc untouched.
+    fn tint(self, color: Color) -> Color {
+        match self {
+            ArcState::Normal => color,
+            ArcState::Muted => scale_color(color, 0.35),
+            ArcState::Soloed => lerp_color(color, Color::WHITE, 0.4),
+        }
+    }
+}
+
+/// Scale each channel of `c` by `factor`, clamping to a valid `u8`.
+fn scale_color(c: Color, factor: f32) -> Color {
+    let scale_channel = |v: u8| ((v as f32 * factor).clamp(0.0, 255.0)) as u8;
+    Color::rgb(scale_channel(c.r), scale_channel(c.g), scale_channel(c.b))
+}
+
+/// Linearly interpolate each channel of `a` toward `b` by `t` (clamped to `[0.0, 1.0]`).
+fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let lerp_channel = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * t) as u8;
+
+    Color::rgb(
+        lerp_channel(a.r, b.r),
+        lerp_channel(a.g, b.g),
+        lerp_channel(a.b, b.b),
+    )
+}